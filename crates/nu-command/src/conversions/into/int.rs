@@ -7,9 +7,57 @@ use nu_protocol::{
 
 struct Arguments {
     radix: Option<Value>,
+    int_type: IntType,
+    overflow: Overflow,
     column_paths: Vec<CellPath>,
 }
 
+/// The width and signedness of the integer type a value is coerced into.
+#[derive(Clone, Copy)]
+pub struct IntType {
+    bits: u32,
+    signed: bool,
+}
+
+impl IntType {
+    fn min(&self) -> i128 {
+        if self.signed {
+            -(1i128 << (self.bits - 1))
+        } else {
+            0
+        }
+    }
+
+    fn max(&self) -> i128 {
+        if self.signed {
+            (1i128 << (self.bits - 1)) - 1
+        } else {
+            (1i128 << self.bits) - 1
+        }
+    }
+
+    fn name(&self) -> String {
+        format!("{}{}", if self.signed { "i" } else { "u" }, self.bits)
+    }
+}
+
+impl Default for IntType {
+    fn default() -> Self {
+        IntType {
+            bits: 64,
+            signed: true,
+        }
+    }
+}
+
+/// What to do when a value does not fit the requested [`IntType`].
+#[derive(Clone, Copy)]
+pub enum Overflow {
+    Error,
+    Wrap,
+    Saturate,
+}
+
 #[derive(Clone)]
 pub struct SubCommand;
 
@@ -21,6 +69,20 @@ impl Command for SubCommand {
     fn signature(&self) -> Signature {
         Signature::build("into int")
             .named("radix", SyntaxShape::Number, "radix of integer", Some('r'))
+            .named(
+                "width",
+                SyntaxShape::Int,
+                "fixed width of the target integer type: 8, 16, 32, or 64 (default 64)",
+                Some('w'),
+            )
+            .switch("signed", "target a signed integer type (the default)", None)
+            .switch("unsigned", "target an unsigned integer type", Some('u'))
+            .named(
+                "overflow",
+                SyntaxShape::String,
+                "behavior when the value does not fit the type: error (default), wrap, or saturate",
+                None,
+            )
             .rest(
                 "rest",
                 SyntaxShape::CellPath,
@@ -91,6 +153,21 @@ impl Command for SubCommand {
                 example: "'FF' |  into int -r 16",
                 result: Some(Value::test_int(255)),
             },
+            Example {
+                description: "Convert octal string to integer",
+                example: "'0o17' | into int",
+                result: Some(Value::test_int(15)),
+            },
+            Example {
+                description: "Wrap a value into an 8-bit unsigned integer",
+                example: "300 | into int --unsigned --width 8 --overflow wrap",
+                result: Some(Value::test_int(44)),
+            },
+            Example {
+                description: "Saturate a value that does not fit a signed 8-bit integer",
+                example: "300 | into int --width 8 --overflow saturate",
+                result: Some(Value::test_int(127)),
+            },
         ]
     }
 }
@@ -103,18 +180,23 @@ fn into_int(
 ) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
     let head = call.head;
 
+    let int_type = int_type_from_call(engine_state, stack, call)?;
+    let overflow = overflow_from_call(engine_state, stack, call)?;
+
     let options = Arguments {
         radix: call.get_flag(engine_state, stack, "radix")?,
+        int_type,
+        overflow,
         column_paths: call.rest(engine_state, stack, 0)?,
     };
 
-    let radix: u32 = match options.radix {
-        Some(Value::Int { val, .. }) => val as u32,
-        Some(_) => 10,
-        None => 10,
+    let radix: Option<u32> = match &options.radix {
+        Some(Value::Int { val, .. }) => Some(*val as u32),
+        Some(_) => Some(10),
+        None => None,
     };
 
-    if let Some(val) = &options.radix {
+    if let (Some(radix), Some(val)) = (radix, &options.radix) {
         if !(2..=36).contains(&radix) {
             return Err(ShellError::UnsupportedInput(
                 "Radix must lie in the range [2, 36]".to_string(),
@@ -123,16 +205,19 @@ fn into_int(
         }
     }
 
+    let int_type = options.int_type;
+    let overflow = options.overflow;
+
     input.map(
         move |v| {
             if options.column_paths.is_empty() {
-                action(&v, head, radix)
+                action(&v, head, radix, int_type, overflow)
             } else {
                 let mut ret = v;
                 for path in &options.column_paths {
                     let r = ret.update_cell_path(
                         &path.members,
-                        Box::new(move |old| action(old, head, radix)),
+                        Box::new(move |old| action(old, head, radix, int_type, overflow)),
                     );
                     if let Err(error) = r {
                         return Value::Error { error };
@@ -146,111 +231,192 @@ fn into_int(
     )
 }
 
-pub fn action(input: &Value, span: Span, radix: u32) -> Value {
-    match input {
-        Value::Int { val: _, .. } => {
-            if radix == 10 {
-                input.clone()
-            } else {
-                convert_int(input, span, radix)
+fn int_type_from_call(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<IntType, ShellError> {
+    let signed = call.has_flag("signed");
+    let unsigned = call.has_flag("unsigned");
+    if signed && unsigned {
+        return Err(ShellError::UnsupportedInput(
+            "'--signed' and '--unsigned' are mutually exclusive".to_string(),
+            call.head,
+        ));
+    }
+
+    let bits = match call.get_flag::<Value>(engine_state, stack, "width")? {
+        Some(Value::Int { val, span }) => match val {
+            8 | 16 | 32 | 64 => val as u32,
+            _ => {
+                return Err(ShellError::UnsupportedInput(
+                    "width must be one of 8, 16, 32, or 64".to_string(),
+                    span,
+                ))
             }
-        }
-        Value::Filesize { val, .. } => Value::Int { val: *val, span },
-        Value::Float { val, .. } => Value::Int {
-            val: *val as i64,
-            span,
         },
-        Value::String { val, .. } => {
-            if radix == 10 {
-                match int_from_string(val, span) {
-                    Ok(val) => Value::Int { val, span },
-                    Err(error) => Value::Error { error },
-                }
-            } else {
-                convert_int(input, span, radix)
+        _ => 64,
+    };
+
+    Ok(IntType {
+        bits,
+        signed: !unsigned,
+    })
+}
+
+fn overflow_from_call(
+    engine_state: &EngineState,
+    stack: &mut Stack,
+    call: &Call,
+) -> Result<Overflow, ShellError> {
+    match call.get_flag::<Value>(engine_state, stack, "overflow")? {
+        Some(Value::String { val, span }) => match val.as_str() {
+            "error" => Ok(Overflow::Error),
+            "wrap" => Ok(Overflow::Wrap),
+            "saturate" => Ok(Overflow::Saturate),
+            _ => Err(ShellError::UnsupportedInput(
+                "overflow must be one of error, wrap, or saturate".to_string(),
+                span,
+            )),
+        },
+        _ => Ok(Overflow::Error),
+    }
+}
+
+pub fn action(
+    input: &Value,
+    span: Span,
+    radix: Option<u32>,
+    int_type: IntType,
+    overflow: Overflow,
+) -> Value {
+    let raw: Result<i128, ShellError> = match input {
+        // An integer is already decoded, so a requested radix only describes how
+        // the value was originally written, not how to re-read it.
+        Value::Int { val, .. } => Ok(*val as i128),
+        Value::Filesize { val, .. } => Ok(*val as i128),
+        Value::Float { val, .. } => Ok(*val as i128),
+        Value::String { val, .. } => match radix {
+            Some(radix) => convert_int(input, span, radix),
+            None => int_from_string(val, span),
+        },
+        Value::Bool { val, .. } => Ok(if *val { 1 } else { 0 }),
+        _ => Err(ShellError::UnsupportedInput(
+            "'into int' for unsupported type".into(),
+            span,
+        )),
+    };
+
+    match raw.and_then(|n| fit(n, int_type, overflow, span)) {
+        Ok(val) => Value::Int { val, span },
+        Err(error) => Value::Error { error },
+    }
+}
+
+/// Clamp, wrap, or check `raw` against the range of the requested [`IntType`].
+fn fit(raw: i128, int_type: IntType, overflow: Overflow, span: Span) -> Result<i64, ShellError> {
+    let min = int_type.min();
+    let max = int_type.max();
+
+    let fitted = if (min..=max).contains(&raw) {
+        raw
+    } else {
+        match overflow {
+            Overflow::Error => {
+                return Err(ShellError::UnsupportedInput(
+                    format!(
+                        "{} is out of range for a {} integer ({}..{})",
+                        raw,
+                        int_type.name(),
+                        min,
+                        max
+                    ),
+                    span,
+                ))
             }
-        }
-        Value::Bool { val, .. } => {
-            if *val {
-                Value::Int { val: 1, span }
-            } else {
-                Value::Int { val: 0, span }
+            Overflow::Saturate => raw.clamp(min, max),
+            Overflow::Wrap => {
+                let modulus = 1i128 << int_type.bits;
+                let wrapped = raw.rem_euclid(modulus);
+                if int_type.signed && wrapped > max {
+                    wrapped - modulus
+                } else {
+                    wrapped
+                }
             }
         }
-        _ => Value::Error {
-            error: ShellError::UnsupportedInput("'into int' for unsupported type".into(), span),
-        },
+    };
+
+    Ok(fitted as i64)
+}
+
+/// Detect a base-denoting prefix (`0b`, `0o`, `0x`), returning the radix it
+/// implies together with the remaining digits.
+fn prefix_radix(trimmed: &str) -> Option<(u32, &str)> {
+    if let Some(digits) = trimmed.strip_prefix("0b") {
+        Some((2, digits))
+    } else if let Some(digits) = trimmed.strip_prefix("0o") {
+        Some((8, digits))
+    } else if let Some(digits) = trimmed.strip_prefix("0x") {
+        Some((16, digits))
+    } else {
+        None
     }
 }
 
-fn convert_int(input: &Value, head: Span, radix: u32) -> Value {
+fn parse_radix(digits: &str, radix: u32, span: Span) -> Result<i128, ShellError> {
+    match i128::from_str_radix(digits, radix) {
+        Ok(n) => Ok(n),
+        Err(reason) => Err(ShellError::CantConvert(
+            "".to_string(),
+            reason.to_string(),
+            span,
+        )),
+    }
+}
+
+fn convert_int(input: &Value, head: Span, radix: u32) -> Result<i128, ShellError> {
     let i = match input {
         Value::Int { val, .. } => val.to_string(),
-        Value::String { val, .. } => {
-            if val.starts_with("0x") || val.starts_with("0b") {
-                match int_from_string(val, head) {
-                    Ok(x) => return Value::Int { val: x, span: head },
-                    Err(e) => return Value::Error { error: e },
-                }
-            }
-            val.to_string()
-        }
+        Value::String { val, .. } => val.to_string(),
         _ => {
-            return Value::Error {
-                error: ShellError::UnsupportedInput(
-                    "only strings or integers are supported".to_string(),
-                    head,
-                ),
-            }
+            return Err(ShellError::UnsupportedInput(
+                "only strings or integers are supported".to_string(),
+                head,
+            ))
         }
     };
-    match i64::from_str_radix(&i, radix) {
-        Ok(n) => Value::Int { val: n, span: head },
-        Err(reason) => Value::Error {
-            error: ShellError::CantConvert("".to_string(), reason.to_string(), head),
-        },
+
+    // A prefix is authoritative: it decides the base, and it is an error to
+    // ask for a `--radix` that disagrees with the prefix.
+    if let Some((base, digits)) = prefix_radix(i.trim()) {
+        if base != radix {
+            return Err(ShellError::UnsupportedInput(
+                format!("'{}' prefix conflicts with radix {}", i.trim(), radix),
+                head,
+            ));
+        }
+        return parse_radix(digits, base, head);
     }
+
+    parse_radix(&i, radix, head)
 }
 
-fn int_from_string(a_string: &str, span: Span) -> Result<i64, ShellError> {
+fn int_from_string(a_string: &str, span: Span) -> Result<i128, ShellError> {
     let trimmed = a_string.trim();
-    match trimmed {
-        b if b.starts_with("0b") => {
-            let num = match i64::from_str_radix(b.trim_start_matches("0b"), 2) {
-                Ok(n) => n,
-                Err(reason) => {
-                    return Err(ShellError::CantConvert(
-                        "could not parse as integer".to_string(),
-                        reason.to_string(),
-                        span,
-                    ))
-                }
-            };
-            Ok(num)
-        }
-        h if h.starts_with("0x") => {
-            let num = match i64::from_str_radix(h.trim_start_matches("0x"), 16) {
-                Ok(n) => n,
-                Err(reason) => {
-                    return Err(ShellError::CantConvert(
-                        "could not parse as int".to_string(),
-                        reason.to_string(),
-                        span,
-                    ))
-                }
-            };
-            Ok(num)
-        }
-        _ => match a_string.parse::<i64>() {
-            Ok(n) => Ok(n),
-            Err(_) => match a_string.parse::<f64>() {
-                Ok(f) => Ok(f as i64),
-                _ => Err(ShellError::CantConvert(
-                    "into int".to_string(),
-                    "string".to_string(),
-                    span,
-                )),
-            },
+    if let Some((base, digits)) = prefix_radix(trimmed) {
+        return parse_radix(digits, base, span);
+    }
+
+    match a_string.parse::<i128>() {
+        Ok(n) => Ok(n),
+        Err(_) => match a_string.parse::<f64>() {
+            Ok(f) => Ok(f as i128),
+            _ => Err(ShellError::CantConvert(
+                "into int".to_string(),
+                "string".to_string(),
+                span,
+            )),
         },
     }
 }
@@ -273,30 +439,132 @@ mod test {
         let word = Value::test_string("10");
         let expected = Value::test_int(10);
 
-        let actual = action(&word, Span::test_data(), 10);
+        let actual = action(&word, Span::test_data(), None, IntType::default(), Overflow::Error);
         assert_eq!(actual, expected);
     }
 
     #[test]
     fn turns_binary_to_integer() {
         let s = Value::test_string("0b101");
-        let actual = action(&s, Span::test_data(), 10);
+        let actual = action(&s, Span::test_data(), None, IntType::default(), Overflow::Error);
         assert_eq!(actual, Value::test_int(5));
     }
 
+    #[test]
+    fn turns_octal_to_integer() {
+        let s = Value::test_string("0o17");
+        let actual = action(&s, Span::test_data(), None, IntType::default(), Overflow::Error);
+        assert_eq!(actual, Value::test_int(15));
+    }
+
+    #[test]
+    fn turns_octal_to_integer_with_explicit_radix() {
+        let s = Value::test_string("17");
+        let actual = action(
+            &s,
+            Span::test_data(),
+            Some(8),
+            IntType::default(),
+            Overflow::Error,
+        );
+        assert_eq!(actual, Value::test_int(15));
+    }
+
     #[test]
     fn turns_hex_to_integer() {
         let s = Value::test_string("0xFF");
-        let actual = action(&s, Span::test_data(), 16);
+        let actual = action(
+            &s,
+            Span::test_data(),
+            Some(16),
+            IntType::default(),
+            Overflow::Error,
+        );
         assert_eq!(actual, Value::test_int(255));
     }
 
+    #[test]
+    fn conflicting_prefix_and_radix_errors() {
+        let s = Value::test_string("0xFF");
+        let actual = action(
+            &s,
+            Span::test_data(),
+            Some(10),
+            IntType::default(),
+            Overflow::Error,
+        );
+        assert_eq!(actual.get_type(), Error);
+    }
+
+    #[test]
+    fn already_decoded_int_is_not_reparsed() {
+        let n = Value::test_int(17);
+        let actual = action(
+            &n,
+            Span::test_data(),
+            Some(8),
+            IntType::default(),
+            Overflow::Error,
+        );
+        assert_eq!(actual, Value::test_int(17));
+    }
+
     #[test]
     fn communicates_parsing_error_given_an_invalid_integerlike_string() {
         let integer_str = Value::test_string("36anra");
 
-        let actual = action(&integer_str, Span::test_data(), 10);
+        let actual = action(
+            &integer_str,
+            Span::test_data(),
+            None,
+            IntType::default(),
+            Overflow::Error,
+        );
 
         assert_eq!(actual.get_type(), Error)
     }
+
+    #[test]
+    fn wraps_unsigned_eight_bit_on_overflow() {
+        let n = Value::test_int(300);
+        let ty = IntType {
+            bits: 8,
+            signed: false,
+        };
+        let actual = action(&n, Span::test_data(), None, ty, Overflow::Wrap);
+        assert_eq!(actual, Value::test_int(44));
+    }
+
+    #[test]
+    fn saturates_signed_eight_bit_on_overflow() {
+        let n = Value::test_int(300);
+        let ty = IntType {
+            bits: 8,
+            signed: true,
+        };
+        let actual = action(&n, Span::test_data(), None, ty, Overflow::Saturate);
+        assert_eq!(actual, Value::test_int(127));
+    }
+
+    #[test]
+    fn errors_on_unsigned_eight_bit_overflow() {
+        let n = Value::test_int(300);
+        let ty = IntType {
+            bits: 8,
+            signed: false,
+        };
+        let actual = action(&n, Span::test_data(), None, ty, Overflow::Error);
+        assert_eq!(actual.get_type(), Error);
+    }
+
+    #[test]
+    fn reinterprets_unsigned_sixty_four_bit_literal() {
+        let s = Value::test_string("FFFFFFFFFFFFFFFF");
+        let ty = IntType {
+            bits: 64,
+            signed: false,
+        };
+        let actual = action(&s, Span::test_data(), Some(16), ty, Overflow::Error);
+        assert_eq!(actual, Value::test_int(-1));
+    }
 }
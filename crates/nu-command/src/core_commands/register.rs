@@ -1,6 +1,6 @@
 use nu_protocol::ast::Call;
 use nu_protocol::engine::{Command, EngineState, Stack};
-use nu_protocol::{Category, Example, PipelineData, Signature, SyntaxShape};
+use nu_protocol::{Category, Example, PipelineData, ShellError, Signature, SyntaxShape};
 
 #[derive(Clone)]
 pub struct Register;
@@ -38,6 +38,11 @@ impl Command for Register {
                 "path of shell used to run plugin (cmd, sh, python, etc)",
                 Some('s'),
             )
+            .switch(
+                "wasm",
+                "WebAssembly plugin modules are not supported: passing this flag returns an error",
+                None,
+            )
             .category(Category::Core)
     }
 
@@ -48,6 +53,17 @@ impl Command for Register {
         call: &Call,
         _input: PipelineData,
     ) -> Result<nu_protocol::PipelineData, nu_protocol::ShellError> {
+        // There is no WebAssembly backend (no embedded runtime, host encode/decode
+        // imports, or Signature discovery across the wasm boundary). `--wasm`
+        // returns an error rather than falling back to spawning a native
+        // executable.
+        if call.has_flag("wasm") {
+            return Err(ShellError::UnsupportedInput(
+                "WebAssembly plugins are not yet supported".to_string(),
+                call.head,
+            ));
+        }
+
         Ok(PipelineData::new(call.head))
     }
 
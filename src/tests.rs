@@ -0,0 +1,107 @@
+use std::io::Write;
+use std::process::Command;
+
+use tempfile::NamedTempFile;
+
+mod test_engine;
+
+pub type TestResult = Result<(), TestError>;
+
+#[derive(Debug)]
+pub enum TestError {
+    Stderr(String),
+}
+
+impl From<std::io::Error> for TestError {
+    fn from(error: std::io::Error) -> Self {
+        TestError::Stderr(error.to_string())
+    }
+}
+
+fn run_commands(input: &str) -> Result<std::process::Output, TestError> {
+    let mut file = NamedTempFile::new()?;
+    let name = file.path();
+
+    let mut cmd = Command::new(env!("CARGO_BIN_EXE_nu"));
+    cmd.arg(name);
+
+    writeln!(file, "{}", input)?;
+
+    Ok(cmd.output()?)
+}
+
+pub fn run_test(input: &str, expected: &str) -> TestResult {
+    let output = run_commands(input)?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    println!("stdout: {}", stdout);
+    println!("stderr: {}", stderr);
+
+    assert!(output.status.success());
+    assert_eq!(stdout.trim(), expected);
+
+    Ok(())
+}
+
+pub fn fail_test(input: &str, expected: &str) -> TestResult {
+    let output = run_commands(input)?;
+
+    let stderr = String::from_utf8_lossy(&output.stderr).to_string();
+
+    println!("stderr: {}", stderr);
+
+    assert!(!stderr.is_empty() && stderr.contains(expected));
+
+    Ok(())
+}
+
+/// Evaluate a sequence of lines as a single script and compare the emitted
+/// output, with ANSI escape sequences stripped first so that colorized output
+/// — e.g. from the `ansi` command or a colored `table`/error render — can be
+/// snapshot-tested as plain text regardless of the active color config. The
+/// lines share one scope and run in order, so `let-env` persistence and other
+/// state carried between lines can be asserted. Driving the engine over a
+/// source file keeps only command output on stdout — no banner or prompt
+/// strings — so the comparison is deterministic.
+pub fn run_repl_test(inputs: &[&str], expected: &str) -> TestResult {
+    let output = run_commands(&inputs.join("\n"))?;
+
+    let stdout = strip_ansi(&String::from_utf8_lossy(&output.stdout));
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    println!("stdout: {}", stdout);
+    println!("stderr: {}", stderr);
+
+    assert!(output.status.success());
+    assert_eq!(stdout.trim(), expected);
+
+    Ok(())
+}
+
+/// Remove ANSI CSI/SGR escape sequences so rendered output can be compared as
+/// plain text.
+fn strip_ansi(input: &str) -> String {
+    let mut output = String::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // A Control Sequence Introducer is `ESC [` followed by parameter and
+            // intermediate bytes and terminated by a final byte in 0x40..=0x7e.
+            if chars.peek() == Some(&'[') {
+                chars.next();
+                for next in chars.by_ref() {
+                    if ('\x40'..='\x7e').contains(&next) {
+                        break;
+                    }
+                }
+            }
+        } else {
+            output.push(c);
+        }
+    }
+
+    output
+}
@@ -1,4 +1,4 @@
-use crate::tests::{fail_test, run_test, TestResult};
+use crate::tests::{fail_test, run_repl_test, run_test, TestResult};
 
 #[test]
 fn concrete_variable_assignment() -> TestResult {
@@ -287,3 +287,20 @@ fn bool_variable() -> TestResult {
 fn bool_variable2() -> TestResult {
     run_test(r#"$false"#, "false")
 }
+
+#[test]
+fn repl_runs_lines_in_one_scope() -> TestResult {
+    run_repl_test(&["let x = 5", "$x + 1"], "6")
+}
+
+#[test]
+fn repl_let_env_persists_across_lines() -> TestResult {
+    run_repl_test(&[r#"let-env FOO = "bar""#, "$env.FOO"], "bar")
+}
+
+#[test]
+fn repl_strips_ansi_escapes() -> TestResult {
+    // `ansi` emits escape codes unconditionally, so this exercises the
+    // stripping path even when stdout is a non-TTY pipe with color disabled.
+    run_repl_test(&[r#"$"(ansi red)hello(ansi reset)""#], "hello")
+}